@@ -1,88 +1,237 @@
 use crate::mem;
+use crate::RangeError;
 
 use bytes::{Bytes, BytesMut};
 
-use std::ops::{RangeFrom, RangeFull, RangeTo, RangeToInclusive};
+use std::ops::{
+    Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+    RangeToInclusive,
+};
+
+// Checks that a single offset (a start or end bound) fits within the buffer.
+#[inline]
+fn check_offset(offset: usize, len: usize) -> Result<(), RangeError> {
+    if offset <= len {
+        Ok(())
+    } else {
+        Err(RangeError::OutOfBounds { len, bound: offset })
+    }
+}
+
+// Checks that an exclusive `start..end` range is valid for a buffer of the
+// given length, i.e. `start <= end <= len`.
+#[inline]
+fn check_bounds(start: usize, end: usize, len: usize) -> Result<(), RangeError> {
+    check_offset(end, len)?;
+    if start <= end {
+        Ok(())
+    } else {
+        Err(RangeError::OutOfBounds { len, bound: start })
+    }
+}
 
 impl_take_range! {
     <RangeFull> for Bytes {
         #[inline]
-        fn take_range(&mut self, _range) {
-            self.split_off(0)
+        fn try_take_range(&mut self, _range) {
+            Ok(self.split_off(0))
         }
         #[inline]
-        fn remove_range(&mut self, _range) {
-            self.clear()
+        fn try_remove_range(&mut self, _range) {
+            self.clear();
+            Ok(())
         }
     }
     <RangeFull> for BytesMut {
         #[inline]
-        fn take_range(&mut self, _range) {
-            self.take()
+        fn try_take_range(&mut self, _range) {
+            Ok(self.take())
         }
         #[inline]
-        fn remove_range(&mut self, _range) {
-            self.clear()
+        fn try_remove_range(&mut self, _range) {
+            self.clear();
+            Ok(())
         }
     }
     <RangeFrom<usize>> for Bytes {
         #[inline]
-        fn take_range(&mut self, range) {
-            self.split_off(range.start)
+        fn try_take_range(&mut self, range) {
+            check_offset(range.start, self.len())?;
+            Ok(self.split_off(range.start))
         }
         #[inline]
-        fn remove_range(&mut self, range) {
-            self.truncate(range.start)
+        fn try_remove_range(&mut self, range) {
+            check_offset(range.start, self.len())?;
+            self.truncate(range.start);
+            Ok(())
         }
     }
     <RangeFrom<usize>> for BytesMut {
         #[inline]
-        fn take_range(&mut self, range) {
-            self.split_off(range.start)
+        fn try_take_range(&mut self, range) {
+            check_offset(range.start, self.len())?;
+            Ok(self.split_off(range.start))
         }
         #[inline]
-        fn remove_range(&mut self, range) {
-            self.truncate(range.start)
+        fn try_remove_range(&mut self, range) {
+            check_offset(range.start, self.len())?;
+            self.truncate(range.start);
+            Ok(())
         }
     }
     <RangeTo<usize>> for Bytes {
         #[inline]
-        fn take_range(&mut self, range) {
-            self.split_to(range.end)
+        fn try_take_range(&mut self, range) {
+            check_offset(range.end, self.len())?;
+            Ok(self.split_to(range.end))
         }
         #[inline]
-        fn remove_range(&mut self, range) {
-            self.advance(range.end)
+        fn try_remove_range(&mut self, range) {
+            check_offset(range.end, self.len())?;
+            self.advance(range.end);
+            Ok(())
         }
     }
     <RangeTo<usize>> for BytesMut {
         #[inline]
-        fn take_range(&mut self, range) {
-            self.split_to(range.end)
+        fn try_take_range(&mut self, range) {
+            check_offset(range.end, self.len())?;
+            Ok(self.split_to(range.end))
         }
         #[inline]
-        fn remove_range(&mut self, range) {
-            self.advance(range.end)
+        fn try_remove_range(&mut self, range) {
+            check_offset(range.end, self.len())?;
+            self.advance(range.end);
+            Ok(())
         }
     }
     <RangeToInclusive<usize>> for Bytes {
         #[inline]
-        fn take_range(&mut self, range) {
-            self.take_range(mem::convert_inclusive_range(range))
+        fn try_take_range(&mut self, range) {
+            let range = mem::convert_inclusive_range_for_len(range, self.len())?;
+            self.try_take_range(range)
         }
         #[inline]
-        fn remove_range(&mut self, range) {
-            self.remove_range(mem::convert_inclusive_range(range))
+        fn try_remove_range(&mut self, range) {
+            let range = mem::convert_inclusive_range_for_len(range, self.len())?;
+            self.try_remove_range(range)
         }
     }
     <RangeToInclusive<usize>> for BytesMut {
         #[inline]
-        fn take_range(&mut self, range) {
-            self.take_range(mem::convert_inclusive_range(range))
+        fn try_take_range(&mut self, range) {
+            let range = mem::convert_inclusive_range_for_len(range, self.len())?;
+            self.try_take_range(range)
+        }
+        #[inline]
+        fn try_remove_range(&mut self, range) {
+            let range = mem::convert_inclusive_range_for_len(range, self.len())?;
+            self.try_remove_range(range)
+        }
+    }
+    <Range<usize>> for Bytes {
+        fn try_take_range(&mut self, range) {
+            let Range { start, end } = range;
+            check_bounds(start, end, self.len())?;
+            let tail = self.split_off(end);
+            let mid = self.split_off(start);
+            // `self` now holds the head; rejoin it with the tail through an
+            // intermediate `BytesMut`, as `Bytes` offers no `unsplit`.
+            let mut rest = BytesMut::with_capacity(self.len() + tail.len());
+            rest.extend_from_slice(&self[..]);
+            rest.extend_from_slice(&tail);
+            *self = rest.freeze();
+            Ok(mid)
+        }
+        fn try_remove_range(&mut self, range) {
+            let Range { start, end } = range;
+            check_bounds(start, end, self.len())?;
+            let tail = self.split_off(end);
+            self.truncate(start);
+            let mut rest = BytesMut::with_capacity(self.len() + tail.len());
+            rest.extend_from_slice(&self[..]);
+            rest.extend_from_slice(&tail);
+            *self = rest.freeze();
+            Ok(())
+        }
+    }
+    <Range<usize>> for BytesMut {
+        fn try_take_range(&mut self, range) {
+            let Range { start, end } = range;
+            check_bounds(start, end, self.len())?;
+            let tail = self.split_off(end);
+            let mid = self.split_off(start);
+            self.unsplit(tail);
+            Ok(mid)
+        }
+        fn try_remove_range(&mut self, range) {
+            let Range { start, end } = range;
+            check_bounds(start, end, self.len())?;
+            let tail = self.split_off(end);
+            self.truncate(start);
+            self.unsplit(tail);
+            Ok(())
+        }
+    }
+    <RangeInclusive<usize>> for Bytes {
+        #[inline]
+        fn try_take_range(&mut self, range) {
+            let range = convert_inclusive(range, self.len())?;
+            self.try_take_range(range)
+        }
+        #[inline]
+        fn try_remove_range(&mut self, range) {
+            let range = convert_inclusive(range, self.len())?;
+            self.try_remove_range(range)
+        }
+    }
+    <RangeInclusive<usize>> for BytesMut {
+        #[inline]
+        fn try_take_range(&mut self, range) {
+            let range = convert_inclusive(range, self.len())?;
+            self.try_take_range(range)
+        }
+        #[inline]
+        fn try_remove_range(&mut self, range) {
+            let range = convert_inclusive(range, self.len())?;
+            self.try_remove_range(range)
+        }
+    }
+    <(Bound<usize>, Bound<usize>)> for Bytes {
+        #[inline]
+        fn try_take_range(&mut self, range) {
+            let range = mem::resolve_range(range, self.len())?;
+            self.try_take_range(range)
+        }
+        #[inline]
+        fn try_remove_range(&mut self, range) {
+            let range = mem::resolve_range(range, self.len())?;
+            self.try_remove_range(range)
+        }
+    }
+    <(Bound<usize>, Bound<usize>)> for BytesMut {
+        #[inline]
+        fn try_take_range(&mut self, range) {
+            let range = mem::resolve_range(range, self.len())?;
+            self.try_take_range(range)
         }
         #[inline]
-        fn remove_range(&mut self, range) {
-            self.remove_range(mem::convert_inclusive_range(range))
+        fn try_remove_range(&mut self, range) {
+            let range = mem::resolve_range(range, self.len())?;
+            self.try_remove_range(range)
         }
     }
 }
+
+// Converts a fully bounded inclusive range into the equivalent exclusive
+// `start..end` range, reusing `mem::convert_inclusive_range_for_len` to
+// resolve the end bound against `len` and handle the `usize::MAX` corner case.
+#[inline]
+fn convert_inclusive(
+    range: RangeInclusive<usize>,
+    len: usize,
+) -> Result<Range<usize>, RangeError> {
+    let (start, end) = range.into_inner();
+    let end = mem::convert_inclusive_range_for_len(..=end, len)?.end;
+    Ok(start..end)
+}
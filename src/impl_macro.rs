@@ -1,20 +1,22 @@
 macro_rules! take_range_method {
     {
         $(#[$attr:ident])*
-        fn take_range(&mut $self:ident, $range:ident: $Range:ty)
+        fn try_take_range(&mut $self:ident, $range:ident: $Range:ty)
         $body:block
     } => {
         $(#[$attr])*
-        fn take_range(&mut $self, $range: $Range) -> Self::Output
+        fn try_take_range(&mut $self, $range: $Range)
+            -> Result<Self::Output, $crate::RangeError>
         $body
     };
     {
         $(#[$attr:ident])*
-        fn remove_range(&mut $self:ident, $range:ident: $Range:ty)
+        fn try_remove_range(&mut $self:ident, $range:ident: $Range:ty)
         $body:block
     } => {
         $(#[$attr])*
-        fn remove_range(&mut $self, $range: $Range)
+        fn try_remove_range(&mut $self, $range: $Range)
+            -> Result<(), $crate::RangeError>
         $body
     };
 }
@@ -51,4 +51,7 @@ mod impl_macro;
 #[cfg(feature = "bytes")]
 mod bytes;
 
-pub use take_range::TakeRange;
+#[cfg(feature = "std")]
+mod std_impls;
+
+pub use take_range::{RangeError, TakeRange};
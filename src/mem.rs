@@ -1,6 +1,8 @@
 //! Utilities for working with ranges of collections in memory.
 
-use core::ops::{RangeTo, RangeToInclusive};
+use crate::RangeError;
+
+use core::ops::{Bound, Range, RangeBounds, RangeTo, RangeToInclusive};
 
 /// Converts a range with an inclusive end bound into the equivalent
 /// range with the exclusive end bound.
@@ -24,3 +26,66 @@ pub fn convert_inclusive_range(
 ) -> RangeTo<usize> {
     ..range.end.checked_add(1).expect("integer overflow")
 }
+
+/// Converts a range with an inclusive end bound into the equivalent range
+/// with the exclusive end bound, resolving the `usize::MAX` corner case
+/// against the collection length `len`.
+///
+/// Unlike [`convert_inclusive_range`], this function does not panic when the
+/// inclusive end bound is `usize::MAX`. Such a bound cannot be incremented to
+/// an exclusive one, but it is meaningful for a collection whose length is
+/// itself `usize::MAX`, where it designates the whole collection; in that
+/// case the exclusive end becomes `len`. For any shorter collection the bound
+/// lies past the end, which is reported as [`RangeError::OutOfBounds`] rather
+/// than an overflow panic.
+#[inline]
+pub fn convert_inclusive_range_for_len(
+    range: RangeToInclusive<usize>,
+    len: usize,
+) -> Result<RangeTo<usize>, RangeError> {
+    match range.end.checked_add(1) {
+        Some(end) => Ok(..end),
+        None if len == usize::MAX => Ok(..len),
+        None => Err(RangeError::OutOfBounds {
+            len,
+            bound: range.end,
+        }),
+    }
+}
+
+/// Resolves an arbitrary range into concrete `start..end` offsets against a
+/// collection of length `len`.
+///
+/// This normalizes a range carried as any `RangeBounds<usize>` type, such as
+/// a `(Bound<usize>, Bound<usize>)` pair computed at run time, into the
+/// bounded `Range<usize>` form consumed by the rest of the machinery. An
+/// unbounded start resolves to `0` and an unbounded end to `len`; an
+/// `Excluded` start or `Included` end is advanced by one, reusing
+/// [`convert_inclusive_range_for_len`] for the end bound so the `usize::MAX`
+/// corner case is handled identically. A bound that overflows while being
+/// advanced is reported as [`RangeError::OutOfBounds`].
+///
+/// The returned range is not otherwise checked against `len`; the caller
+/// feeds it into the bounded-range machinery, which validates
+/// `start <= end <= len`.
+pub fn resolve_range<R>(range: R, len: usize) -> Result<Range<usize>, RangeError>
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Unbounded => 0,
+        Bound::Included(&index) => index,
+        Bound::Excluded(&index) => match index.checked_add(1) {
+            Some(start) => start,
+            None => return Err(RangeError::OutOfBounds { len, bound: index }),
+        },
+    };
+    let end = match range.end_bound() {
+        Bound::Unbounded => len,
+        Bound::Excluded(&index) => index,
+        Bound::Included(&index) => {
+            convert_inclusive_range_for_len(..=index, len)?.end
+        }
+    };
+    Ok(start..end)
+}
@@ -0,0 +1,165 @@
+use crate::mem;
+use crate::str::{check_range, StrRangeError};
+use crate::{RangeError, TakeRange};
+
+use std::collections::VecDeque;
+use std::ops::{
+    Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+    RangeToInclusive,
+};
+
+// Resolves an arbitrary range into checked `start..end` offsets for a
+// sequence of the given length, returning `RangeError::OutOfBounds` rather
+// than letting `drain` panic on an argument that originates from untrusted
+// input. Inclusive end bounds are routed through the same length-aware
+// conversion the `bytes` impls use, so `..=usize::MAX` is handled uniformly.
+#[inline]
+fn resolve_checked<R>(range: R, len: usize) -> Result<Range<usize>, RangeError>
+where
+    R: RangeBounds<usize>,
+{
+    let Range { start, end } = mem::resolve_range(range, len)?;
+    if end > len {
+        Err(RangeError::OutOfBounds { len, bound: end })
+    } else if start > end {
+        Err(RangeError::OutOfBounds { len, bound: start })
+    } else {
+        Ok(start..end)
+    }
+}
+
+// Generates `TakeRange` impls for the standard sequence containers. Each range
+// is resolved and bounds-checked through `resolve_checked` before `drain` is
+// called. `take_range` collects the drained items into a fresh container of
+// the same type, while `remove_range` drops the drain iterator without
+// materializing them.
+macro_rules! impl_drain_take_range {
+    ($($T:ty),* $(,)?) => {
+        $(
+            impl<T> TakeRange<$T> for Vec<T> {
+                type Output = Vec<T>;
+
+                fn try_take_range(
+                    &mut self,
+                    range: $T,
+                ) -> Result<Self::Output, RangeError> {
+                    let range = resolve_checked(range, self.len())?;
+                    Ok(self.drain(range).collect())
+                }
+
+                fn try_remove_range(
+                    &mut self,
+                    range: $T,
+                ) -> Result<(), RangeError> {
+                    let range = resolve_checked(range, self.len())?;
+                    self.drain(range);
+                    Ok(())
+                }
+            }
+
+            impl<T> TakeRange<$T> for VecDeque<T> {
+                type Output = VecDeque<T>;
+
+                fn try_take_range(
+                    &mut self,
+                    range: $T,
+                ) -> Result<Self::Output, RangeError> {
+                    let range = resolve_checked(range, self.len())?;
+                    Ok(self.drain(range).collect())
+                }
+
+                fn try_remove_range(
+                    &mut self,
+                    range: $T,
+                ) -> Result<(), RangeError> {
+                    let range = resolve_checked(range, self.len())?;
+                    self.drain(range);
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_drain_take_range! {
+    RangeFull,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeToInclusive<usize>,
+    Range<usize>,
+    RangeInclusive<usize>,
+    (Bound<usize>, Bound<usize>),
+}
+
+// Validates a range against the string through the `str` module, mapping the
+// UTF-8-aware `StrRangeError` onto the trait's own `RangeError` so that a
+// split inside a code point or past the end surfaces the same way as for the
+// other containers. A range whose start lies past its end is then rejected
+// through `resolve_checked`, as for the other containers.
+#[inline]
+fn check_str_range<R>(s: &str, range: &R) -> Result<(), RangeError>
+where
+    R: RangeBounds<usize>,
+{
+    check_range(s, range).map_err(|e| match e {
+        StrRangeError::OutOfBounds { index } => RangeError::OutOfBounds {
+            len: s.len(),
+            bound: index,
+        },
+        StrRangeError::NotCharBoundary { index } => {
+            RangeError::NotCharBoundary { index }
+        }
+    })?;
+    let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+    resolve_checked(bounds, s.len()).map(drop)
+}
+
+// Generates `TakeRange` impls for `String`. Unlike the sequence containers,
+// a split point must fall on a UTF-8 code point boundary, so each range is
+// validated through the `str` module before it reaches `String::drain`.
+macro_rules! impl_string_take_range {
+    ($($T:ty),* $(,)?) => {
+        $(
+            impl TakeRange<$T> for String {
+                type Output = String;
+
+                fn try_take_range(
+                    &mut self,
+                    range: $T,
+                ) -> Result<Self::Output, RangeError> {
+                    check_str_range(self, &range)?;
+                    Ok(self.drain(range).collect())
+                }
+
+                fn try_remove_range(
+                    &mut self,
+                    range: $T,
+                ) -> Result<(), RangeError> {
+                    check_str_range(self, &range)?;
+                    self.drain(range);
+                    Ok(())
+                }
+
+                fn take_range(&mut self, range: $T) -> Self::Output {
+                    $crate::assert_str_range!(*self, range);
+                    self.drain(range).collect()
+                }
+
+                fn remove_range(&mut self, range: $T) {
+                    $crate::assert_str_range!(*self, range);
+                    self.drain(range);
+                }
+            }
+        )*
+    };
+}
+
+impl_string_take_range! {
+    RangeFull,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeToInclusive<usize>,
+    Range<usize>,
+    RangeInclusive<usize>,
+    (Bound<usize>, Bound<usize>),
+}
@@ -1,5 +1,6 @@
 //! Utilities for validating ranges on UTF-8 strings.
 
+use core::fmt;
 use core::ops::{Bound, RangeBounds};
 
 /// Asserts that the given range is valid for the given string slice.
@@ -55,9 +56,86 @@ where
         && validate_end_bound(s, range.end_bound()).is_ok()
 }
 
+/// Checks that `range` is valid for splitting the string slice `s`,
+/// returning a structured reason on failure.
+///
+/// The range is valid if it fits within the slice and its bounds are
+/// on UTF-8 code point boundaries. Unlike [`is_valid_range`], which only
+/// reports whether the range is valid, this function reports *why* it is
+/// not through a [`StrRangeError`], so callers can produce a precise
+/// diagnostic instead of relying on [`assert_str_range!`] and catching the
+/// unwind.
+///
+/// # Examples
+///
+/// ```
+/// # use range_split::str::check_range;
+/// assert!(check_range("Привет", &(..1)).is_err());
+/// assert!(check_range("Привет", &(..2)).is_ok());
+/// ```
+pub fn check_range<S, R>(s: S, range: &R) -> Result<(), StrRangeError>
+where
+    S: AsRef<str>,
+    R: RangeBounds<usize>,
+{
+    let s = s.as_ref();
+    validate_start_bound(s, range.start_bound())
+        .and_then(|()| validate_end_bound(s, range.end_bound()))
+        .map_err(StrRangeError::from)
+}
+
+/// The reason why a range was not valid for a string slice, as reported by
+/// [`check_range`].
+///
+/// Like [`core::str::Utf8Error`], the error carries the byte index that
+/// locates the problem within the slice.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StrRangeError {
+    /// A bound fell outside the string slice.
+    OutOfBounds {
+        /// The byte index that fell outside the slice.
+        index: usize,
+    },
+    /// A bound fell inside a multi-byte UTF-8 code point.
+    NotCharBoundary {
+        /// The byte index that did not fall on a code point boundary.
+        index: usize,
+    },
+}
+
+impl fmt::Display for StrRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StrRangeError::OutOfBounds { index } => {
+                write!(f, "byte index {} is out of bounds of the string", index)
+            }
+            StrRangeError::NotCharBoundary { index } => write!(
+                f,
+                "byte index {} is not a UTF-8 code point boundary",
+                index
+            ),
+        }
+    }
+}
+
+impl std::error::Error for StrRangeError {}
+
 enum InvalidBound {
-    OutOfBuffer,
-    NotCharBoundary,
+    OutOfBuffer { index: usize },
+    NotCharBoundary { index: usize },
+}
+
+impl From<InvalidBound> for StrRangeError {
+    fn from(bound: InvalidBound) -> Self {
+        match bound {
+            InvalidBound::OutOfBuffer { index } => {
+                StrRangeError::OutOfBounds { index }
+            }
+            InvalidBound::NotCharBoundary { index } => {
+                StrRangeError::NotCharBoundary { index }
+            }
+        }
+    }
 }
 
 #[inline]
@@ -97,9 +175,9 @@ fn validate_index(s: &str, index: usize) -> Result<(), InvalidBound> {
     if s.is_char_boundary(index) {
         Ok(())
     } else if index > s.len() {
-        Err(OutOfBuffer)
+        Err(OutOfBuffer { index })
     } else {
-        Err(NotCharBoundary)
+        Err(NotCharBoundary { index })
     }
 }
 
@@ -110,11 +188,11 @@ fn validate_next_index(s: &str, index: usize) -> Result<(), InvalidBound> {
     // The check for OOB also rules out integer overflow in index + 1
     if index >= s.len() {
         #[cold]
-        Err(OutOfBuffer)
+        Err(OutOfBuffer { index })
     } else if s.is_char_boundary(index + 1) {
         Ok(())
     } else {
-        Err(NotCharBoundary)
+        Err(NotCharBoundary { index: index + 1 })
     }
 }
 
@@ -139,10 +217,10 @@ fn range_fail_internal(
     let end_validity = validate_end_bound(s, end_bound);
     let r = (start_bound, end_bound);
     match (start_validity, end_validity) {
-        (Err(OutOfBuffer), _) | (_, Err(OutOfBuffer)) => {
+        (Err(OutOfBuffer { .. }), _) | (_, Err(OutOfBuffer { .. })) => {
             panic!("range {:?} is out of bounds", r)
         }
-        (Err(NotCharBoundary), _) | (_, Err(NotCharBoundary)) => {
+        (Err(NotCharBoundary { .. }), _) | (_, Err(NotCharBoundary { .. })) => {
             panic!("range {:?} does not split on a UTF-8 boundary", r)
         }
         (Ok(()), Ok(())) => unreachable!("there was no problem with the range"),
@@ -1,9 +1,87 @@
+use core::fmt;
+
+/// The error returned by the fallible methods of [`TakeRange`] when a range
+/// is not valid for the operation.
+///
+/// This is the non-panicking counterpart to the failures reported by
+/// `take_range` and `remove_range`, allowing callers to validate ranges
+/// that originate from untrusted input, such as a length field of a
+/// network frame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RangeError {
+    /// The range, or one of its bounds, falls outside the collection, or the
+    /// start of the range lies past its end.
+    ///
+    /// `len` is the length of the collection and `bound` is the offending
+    /// bound value: the one that exceeded `len`, or the start bound of a
+    /// range whose start is greater than its end.
+    OutOfBounds {
+        /// The length of the collection the range was applied to.
+        len: usize,
+        /// The offending bound value.
+        bound: usize,
+    },
+    /// A bound did not fall on a UTF-8 code point boundary.
+    ///
+    /// This can only arise for collections whose contents are constrained
+    /// to valid UTF-8, such as `String`.
+    NotCharBoundary {
+        /// The byte index that did not fall on a code point boundary.
+        index: usize,
+    },
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::OutOfBounds { len, bound } => write!(
+                f,
+                "range bound {} is out of bounds for a collection of length {}",
+                bound, len
+            ),
+            RangeError::NotCharBoundary { index } => write!(
+                f,
+                "index {} does not fall on a UTF-8 code point boundary",
+                index
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
+
 /// Methods for splitting out part of a collection with a given range.
 pub trait TakeRange<R> {
     /// The value returned by the `take_range` method, representing
     /// the extracted part of the collection.
     type Output;
 
+    /// Splits off and returns part of the collection designated by
+    /// the given range, or a [`RangeError`] if the range is not valid for
+    /// the operation. The remaining part is left in `self` with indices
+    /// adjusted after the removal.
+    ///
+    /// The range parameter typically has one of the standard range types
+    /// constructed with [range expression][range-expr] syntax.
+    ///
+    /// [range-expr]: https://doc.rust-lang.org/reference/expressions/range-expr.html
+    fn try_take_range(&mut self, range: R) -> Result<Self::Output, RangeError>;
+
+    /// Removes items from the collection as designated by the given range,
+    /// or returns a [`RangeError`] if the range is not valid for the
+    /// operation. The remaining part is left in `self` with indices
+    /// adjusted after the removal.
+    ///
+    /// The default implementation of this method calls `try_take_range` and
+    /// drops the returned value. Implementors of the trait should consider
+    /// a more efficient implementation, avoiding construction of an
+    /// intermediate container.
+    ///
+    /// [range-expr]: https://doc.rust-lang.org/reference/expressions/range-expr.html
+    fn try_remove_range(&mut self, range: R) -> Result<(), RangeError> {
+        self.try_take_range(range).map(drop)
+    }
+
     /// Splits off and returns part of the collection designated by
     /// the given range. The remaining part is left in `self` with indices
     /// adjusted after the removal.
@@ -15,29 +93,27 @@ pub trait TakeRange<R> {
     ///
     /// # Panics
     ///
-    /// The implementation can panic if the range is not valid for the
-    /// operation.
-    fn take_range(&mut self, range: R) -> Self::Output;
+    /// Panics if the range is not valid for the operation. Use
+    /// [`try_take_range`][Self::try_take_range] for a non-panicking variant.
+    fn take_range(&mut self, range: R) -> Self::Output {
+        self.try_take_range(range).unwrap()
+    }
 
-    /// Removes items from the the collection as designated by
+    /// Removes items from the collection as designated by
     /// the given range. The remaining part is left in `self` with indices
     /// adjusted after the removal.
     ///
     /// The range parameter typically has one of the standard range types
     /// constructed with [range expression][range-expr] syntax.
     ///
-    /// The default implementation of this method calls `take_range` and
-    /// drops the returned value. Implementors of the trait should consider
-    /// a more efficient implementation, avoiding construction of an
-    /// intermediate container.
-    ///
     /// [range-expr]: https://doc.rust-lang.org/reference/expressions/range-expr.html
     ///
     /// # Panics
     ///
-    /// The implementation can panic if the range is not valid for the
-    /// operation.
+    /// Panics if the range is not valid for the operation. Use
+    /// [`try_remove_range`][Self::try_remove_range] for a non-panicking
+    /// variant.
     fn remove_range(&mut self, range: R) {
-        self.take_range(range);
+        self.try_remove_range(range).unwrap()
     }
 }
@@ -0,0 +1,35 @@
+#![cfg(feature = "bytes")]
+
+use bytes::{Bytes, BytesMut};
+use range_split::TakeRange;
+
+#[test]
+fn bytes_take_middle_range() {
+    let mut buf = Bytes::from_static(b"Hello, world");
+    let mid = buf.take_range(2..5);
+    assert_eq!(mid, Bytes::from_static(b"llo"));
+    assert_eq!(buf, Bytes::from_static(b"He, world"));
+}
+
+#[test]
+fn bytes_remove_middle_range() {
+    let mut buf = Bytes::from_static(b"Hello, world");
+    buf.remove_range(2..5);
+    assert_eq!(buf, Bytes::from_static(b"He, world"));
+}
+
+#[test]
+fn bytes_mut_take_middle_range() {
+    let mut buf = BytesMut::from(&b"Hello, world"[..]);
+    let mid = buf.take_range(2..5);
+    assert_eq!(&mid[..], b"llo");
+    assert_eq!(&buf[..], b"He, world");
+}
+
+#[test]
+fn take_inclusive_middle_range() {
+    let mut buf = Bytes::from_static(b"Hello, world");
+    let mid = buf.take_range(2..=4);
+    assert_eq!(mid, Bytes::from_static(b"llo"));
+    assert_eq!(buf, Bytes::from_static(b"He, world"));
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "bytes")]
+
+use bytes::Bytes;
+use range_split::{RangeError, TakeRange};
+
+#[test]
+fn try_take_range_ok_returns_extracted() {
+    let mut buf = Bytes::from_static(b"abcdef");
+    assert_eq!(buf.try_take_range(1..3), Ok(Bytes::from_static(b"bc")));
+    assert_eq!(buf, Bytes::from_static(b"adef"));
+}
+
+#[test]
+fn try_take_range_reports_out_of_bounds() {
+    let mut buf = Bytes::from_static(b"abc");
+    assert_eq!(
+        buf.try_take_range(0..99),
+        Err(RangeError::OutOfBounds { len: 3, bound: 99 })
+    );
+    // The buffer is left untouched when the range is rejected.
+    assert_eq!(buf, Bytes::from_static(b"abc"));
+}
+
+#[test]
+fn try_remove_range_reports_reversed_bounds() {
+    let mut buf = Bytes::from_static(b"abcdef");
+    assert_eq!(
+        buf.try_remove_range(4..2),
+        Err(RangeError::OutOfBounds { len: 6, bound: 4 })
+    );
+    assert_eq!(buf, Bytes::from_static(b"abcdef"));
+}
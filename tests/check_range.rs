@@ -0,0 +1,36 @@
+use std::ops::Bound;
+
+use range_split::str::{check_range, StrRangeError};
+
+#[test]
+fn accepts_valid_boundaries() {
+    assert_eq!(check_range("Привет", &(..2)), Ok(()));
+    assert_eq!(check_range("Привет", &(2..)), Ok(()));
+}
+
+#[test]
+fn reports_split_inside_code_point() {
+    assert_eq!(
+        check_range("Привет", &(..1)),
+        Err(StrRangeError::NotCharBoundary { index: 1 })
+    );
+}
+
+#[test]
+fn reports_out_of_bounds() {
+    assert_eq!(
+        check_range("abc", &(..4)),
+        Err(StrRangeError::OutOfBounds { index: 4 })
+    );
+}
+
+#[test]
+fn excluded_start_validates_next_index() {
+    // An excluded start bound validates the following index; byte 1 lands
+    // inside the first two-byte code point, so the reported index is 0 + 1.
+    let range = (Bound::Excluded(0), Bound::Unbounded);
+    assert_eq!(
+        check_range("Привет", &range),
+        Err(StrRangeError::NotCharBoundary { index: 1 })
+    );
+}
@@ -0,0 +1,26 @@
+use range_split::mem::convert_inclusive_range_for_len;
+use range_split::RangeError;
+
+#[test]
+fn converts_inclusive_end_to_exclusive() {
+    assert_eq!(convert_inclusive_range_for_len(..=4, 8), Ok(..5));
+}
+
+#[test]
+fn max_end_spans_full_length_collection() {
+    assert_eq!(
+        convert_inclusive_range_for_len(..=usize::MAX, usize::MAX),
+        Ok(..usize::MAX)
+    );
+}
+
+#[test]
+fn max_end_out_of_bounds_for_shorter_collection() {
+    assert_eq!(
+        convert_inclusive_range_for_len(..=usize::MAX, 8),
+        Err(RangeError::OutOfBounds {
+            len: 8,
+            bound: usize::MAX
+        })
+    );
+}
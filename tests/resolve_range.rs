@@ -0,0 +1,34 @@
+use std::ops::Bound;
+
+use range_split::mem::resolve_range;
+use range_split::RangeError;
+
+#[test]
+fn resolves_bound_tuple_into_offsets() {
+    let range = (Bound::Included(2), Bound::Excluded(5));
+    assert_eq!(resolve_range(range, 8), Ok(2..5));
+}
+
+#[test]
+fn resolves_unbounded_ends_against_length() {
+    let range: (Bound<usize>, Bound<usize>) = (Bound::Unbounded, Bound::Unbounded);
+    assert_eq!(resolve_range(range, 8), Ok(0..8));
+}
+
+#[test]
+fn advances_excluded_start_and_included_end() {
+    let range = (Bound::Excluded(1), Bound::Included(4));
+    assert_eq!(resolve_range(range, 8), Ok(2..5));
+}
+
+#[test]
+fn excluded_start_overflow_is_out_of_bounds() {
+    let range = (Bound::Excluded(usize::MAX), Bound::Unbounded);
+    assert_eq!(
+        resolve_range(range, 8),
+        Err(RangeError::OutOfBounds {
+            len: 8,
+            bound: usize::MAX
+        })
+    );
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "std")]
+
+use range_split::{RangeError, TakeRange};
+use std::collections::VecDeque;
+
+#[test]
+fn vec_take_range_out_of_bounds_is_err() {
+    let mut v = vec![1, 2, 3];
+    assert_eq!(
+        v.try_take_range(0..99),
+        Err(RangeError::OutOfBounds { len: 3, bound: 99 })
+    );
+    // The vector is left untouched when the range is rejected.
+    assert_eq!(v, vec![1, 2, 3]);
+}
+
+#[test]
+fn vec_take_range_removes_middle() {
+    let mut v = vec![1, 2, 3, 4, 5];
+    let mid = v.take_range(1..3);
+    assert_eq!(mid, vec![2, 3]);
+    assert_eq!(v, vec![1, 4, 5]);
+}
+
+#[test]
+fn vec_deque_inclusive_max_is_err_not_panic() {
+    let mut v: VecDeque<u8> = VecDeque::from(vec![1, 2, 3]);
+    assert_eq!(
+        v.try_remove_range(..=usize::MAX),
+        Err(RangeError::OutOfBounds {
+            len: 3,
+            bound: usize::MAX
+        })
+    );
+}